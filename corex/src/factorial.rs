@@ -0,0 +1,115 @@
+//! Factorial implementations used by the benchmark suite and the Axum
+//! compute endpoints. The `u64` variants overflow past `n=20`; use the
+//! `_big` variants for larger inputs.
+
+use num_bigint::BigUint;
+
+/// Naive recursive factorial.
+pub fn factorial(n: u64) -> u64 {
+    match n {
+        0 | 1 => 1,
+        _ => n * factorial(n - 1),
+    }
+}
+
+/// Iterative factorial.
+pub fn it_factorial(n: u64) -> u64 {
+    let mut result = 1u64;
+    for i in 2..=n {
+        result *= i;
+    }
+    result
+}
+
+/// Memoized factorial.
+pub fn mem_factorial(n: u64) -> u64 {
+    fn mem_factorial_helper(n: u64, memo: &mut Vec<Option<u64>>) -> u64 {
+        if let Some(result) = memo[n as usize] {
+            return result;
+        }
+
+        let result = match n {
+            0 | 1 => 1,
+            _ => n * mem_factorial_helper(n - 1, memo),
+        };
+
+        memo[n as usize] = Some(result);
+        result
+    }
+
+    let mut memo = vec![None; (n + 1) as usize];
+    mem_factorial_helper(n, &mut memo)
+}
+
+/// Recursive factorial using arbitrary-precision integers.
+pub fn factorial_big(n: u64) -> BigUint {
+    match n {
+        0 | 1 => BigUint::from(1u32),
+        _ => BigUint::from(n) * factorial_big(n - 1),
+    }
+}
+
+/// Iterative factorial using arbitrary-precision integers.
+pub fn it_factorial_big(n: u64) -> BigUint {
+    let mut result = BigUint::from(1u32);
+    for i in 2..=n {
+        result *= i;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factorial_correctness() {
+        let expected = [1, 1, 2, 6, 24, 120, 720, 5040];
+
+        for (n, &expected_val) in expected.iter().enumerate() {
+            let n = n as u64;
+            assert_eq!(factorial(n), expected_val, "recursive failed for n={}", n);
+            assert_eq!(
+                it_factorial(n),
+                expected_val,
+                "iterative failed for n={}",
+                n
+            );
+            assert_eq!(
+                mem_factorial(n),
+                expected_val,
+                "memoized failed for n={}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_large_values() {
+        // Test that all implementations give same results
+        // Note: like the other variants, this overflows u64 past n=20.
+        for n in 10..=20 {
+            let iterative_result = it_factorial(n);
+            assert_eq!(factorial(n), iterative_result);
+            assert_eq!(mem_factorial(n), iterative_result);
+        }
+    }
+
+    #[test]
+    fn test_factorial_big_correctness() {
+        let expected: Vec<u64> = vec![1, 1, 2, 6, 24, 120];
+
+        for (n, &expected_val) in expected.iter().enumerate() {
+            let n = n as u64;
+            assert_eq!(it_factorial_big(n), BigUint::from(expected_val));
+            assert_eq!(factorial_big(n), BigUint::from(expected_val));
+        }
+    }
+
+    #[test]
+    fn test_factorial_big_agrees_past_u64_ceiling() {
+        for n in [21u64, 35, 50] {
+            assert_eq!(it_factorial_big(n), factorial_big(n));
+        }
+    }
+}
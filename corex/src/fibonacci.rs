@@ -0,0 +1,233 @@
+//! Fibonacci implementations used by the benchmark suite and the Axum
+//! compute endpoints. The `u64` variants overflow past `n=93`; use the
+//! `_big` variants for larger inputs.
+
+use num_bigint::BigUint;
+
+/// Recursive Fibonacci (inefficient).
+pub fn fib_recursive(n: u32) -> u64 {
+    match n {
+        0 => 0,
+        1 => 1,
+        _ => fib_recursive(n - 1) + fib_recursive(n - 2),
+    }
+}
+
+/// Iterative Fibonacci (efficient).
+pub fn fib_iterative(n: u32) -> u64 {
+    match n {
+        0 => 0,
+        1 => 1,
+        _ => {
+            let mut a = 0u64;
+            let mut b = 1u64;
+            for _ in 2..=n {
+                let temp = a + b;
+                a = b;
+                b = temp;
+            }
+            b
+        }
+    }
+}
+
+/// Memoized Fibonacci.
+pub fn fib_memoized(n: u32) -> u64 {
+    fn fib_memo_helper(n: u32, memo: &mut Vec<Option<u64>>) -> u64 {
+        if let Some(result) = memo[n as usize] {
+            return result;
+        }
+
+        let result = match n {
+            0 => 0,
+            1 => 1,
+            _ => fib_memo_helper(n - 1, memo) + fib_memo_helper(n - 2, memo),
+        };
+
+        memo[n as usize] = Some(result);
+        result
+    }
+
+    let mut memo = vec![None; (n + 1) as usize];
+    fib_memo_helper(n, &mut memo)
+}
+
+/// Matrix-exponentiation Fibonacci (O(log n)).
+pub fn fib_matrix(n: u32) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+
+    fn matrix_mult(a: [[u64; 2]; 2], b: [[u64; 2]; 2]) -> [[u64; 2]; 2] {
+        [
+            [
+                a[0][0] * b[0][0] + a[0][1] * b[1][0],
+                a[0][0] * b[0][1] + a[0][1] * b[1][1],
+            ],
+            [
+                a[1][0] * b[0][0] + a[1][1] * b[1][0],
+                a[1][0] * b[0][1] + a[1][1] * b[1][1],
+            ],
+        ]
+    }
+
+    fn matrix_pow(m: [[u64; 2]; 2], n: u32) -> [[u64; 2]; 2] {
+        if n == 1 {
+            return m;
+        }
+
+        let half = matrix_pow(m, n / 2);
+        let half_squared = matrix_mult(half, half);
+
+        if n.is_multiple_of(2) {
+            half_squared
+        } else {
+            matrix_mult(half_squared, m)
+        }
+    }
+
+    if n == 1 {
+        return 1;
+    }
+
+    // M^(n-1) = [[F(n), F(n-1)], [F(n-1), F(n-2)]]; raising to n instead
+    // would also compute F(n+1), which does not fit in u64 for n = 93.
+    let base = [[1, 1], [1, 0]];
+    let result = matrix_pow(base, n - 1);
+    result[0][0]
+}
+
+/// Fast-doubling Fibonacci (O(log n)).
+///
+/// Computes F(n) and F(n+1) together by scanning the bits of n from most
+/// significant to least. At each step, given (F(k), F(k+1)):
+///   F(2k)   = F(k) * (2*F(k+1) - F(k))
+///   F(2k+1) = F(k)^2 + F(k+1)^2
+/// and the current bit selects whether we keep (F(2k), F(2k+1)) or advance
+/// to (F(2k+1), F(2k+2)).
+pub fn fib_fast_doubling(n: u32) -> u64 {
+    fn helper(n: u32) -> (u64, u64) {
+        if n == 0 {
+            return (0, 1);
+        }
+
+        let (a, b) = helper(n / 2);
+        let c = a * (2 * b - a);
+        let d = a * a + b * b;
+
+        if n.is_multiple_of(2) {
+            (c, d)
+        } else {
+            (d, c + d)
+        }
+    }
+
+    // F(n+1) does not fit in u64 for n = 93, so the last doubling step
+    // computes only F(n) instead of the full pair.
+    let (a, b) = helper(n / 2);
+    if n.is_multiple_of(2) {
+        a * (2 * b - a)
+    } else {
+        a * a + b * b
+    }
+}
+
+/// Iterative Fibonacci using arbitrary-precision integers.
+pub fn fib_iterative_big(n: u64) -> BigUint {
+    let (mut a, mut b) = (BigUint::from(0u32), BigUint::from(1u32));
+    if n == 0 {
+        return a;
+    }
+    for _ in 2..=n {
+        let temp = &a + &b;
+        a = b;
+        b = temp;
+    }
+    b
+}
+
+/// Fast-doubling Fibonacci using arbitrary-precision integers (O(log n)).
+pub fn fib_fast_doubling_big(n: u64) -> BigUint {
+    fn helper(n: u64) -> (BigUint, BigUint) {
+        if n == 0 {
+            return (BigUint::from(0u32), BigUint::from(1u32));
+        }
+
+        let (a, b) = helper(n / 2);
+        let c = &a * (&b * 2u32 - &a);
+        let d = &a * &a + &b * &b;
+
+        if n.is_multiple_of(2) {
+            (c, d)
+        } else {
+            let next = &c + &d;
+            (d, next)
+        }
+    }
+
+    helper(n).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fibonacci_correctness() {
+        let expected = vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+
+        for (i, &expected_val) in expected.iter().enumerate() {
+            let n = i as u32;
+            assert_eq!(
+                fib_recursive(n),
+                expected_val,
+                "recursive failed for n={}",
+                n
+            );
+            assert_eq!(
+                fib_iterative(n),
+                expected_val,
+                "iterative failed for n={}",
+                n
+            );
+            assert_eq!(fib_memoized(n), expected_val, "memoized failed for n={}", n);
+            assert_eq!(fib_matrix(n), expected_val, "matrix failed for n={}", n);
+            assert_eq!(
+                fib_fast_doubling(n),
+                expected_val,
+                "fast_doubling failed for n={}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn test_large_values() {
+        // Test that all implementations give same results
+        // Note: like the other variants, this overflows u64 past n=93.
+        for n in 20..=40 {
+            let iterative_result = fib_iterative(n);
+            assert_eq!(fib_memoized(n), iterative_result);
+            assert_eq!(fib_matrix(n), iterative_result);
+            assert_eq!(fib_fast_doubling(n), iterative_result);
+        }
+    }
+
+    #[test]
+    fn test_fib_big_correctness() {
+        let expected: Vec<u32> = vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55];
+
+        for (i, &expected_val) in expected.iter().enumerate() {
+            let n = i as u64;
+            assert_eq!(fib_iterative_big(n), BigUint::from(expected_val));
+            assert_eq!(fib_fast_doubling_big(n), BigUint::from(expected_val));
+        }
+    }
+
+    #[test]
+    fn test_fib_big_agrees_past_u64_ceiling() {
+        for n in [94u64, 100, 200] {
+            assert_eq!(fib_iterative_big(n), fib_fast_doubling_big(n));
+        }
+    }
+}
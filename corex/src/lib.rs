@@ -1,3 +1,6 @@
+pub mod factorial;
+pub mod fibonacci;
+
 ///```rust
 /// assert!(true);
 /// ```
@@ -9,7 +12,7 @@ pub fn add(left: u64, right: u64) -> u64 {
 
 
 
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct User {
     pub name: String,
     pub age: u8,
@@ -42,6 +45,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::assertions_on_constants)]
     fn lets_go() {
         assert!(true);
     }
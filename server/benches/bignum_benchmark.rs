@@ -0,0 +1,57 @@
+// Arbitrary-precision benchmarks for the Fibonacci/factorial algorithms in
+// `corex`. These never overflow, so they let us benchmark the asymptotic
+// gap between the iterative and log-time algorithms at inputs far beyond
+// the u64 ceiling (n=93 for Fibonacci, n=20 for factorial).
+
+use std::hint::black_box;
+
+use corex::factorial::{factorial_big, it_factorial_big};
+use corex::fibonacci::{fib_fast_doubling_big, fib_iterative_big};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn benchmark_fib_iterative_big(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fibonacci_iterative_big");
+
+    for n in [100u64, 500, 1_000, 5_000, 10_000].iter() {
+        group.bench_with_input(BenchmarkId::from_parameter(n), n, |b, &n| {
+            b.iter(|| fib_iterative_big(black_box(n)));
+        });
+    }
+
+    group.finish();
+}
+
+fn benchmark_fib_fast_doubling_big(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fibonacci_fast_doubling_big");
+
+    for n in [100u64, 500, 1_000, 5_000, 10_000].iter() {
+        group.bench_with_input(BenchmarkId::from_parameter(n), n, |b, &n| {
+            b.iter(|| fib_fast_doubling_big(black_box(n)));
+        });
+    }
+
+    group.finish();
+}
+
+fn benchmark_factorial_big(c: &mut Criterion) {
+    let mut group = c.benchmark_group("factorial_big");
+
+    for n in [100u64, 500, 1_000, 2_000].iter() {
+        group.bench_with_input(BenchmarkId::new("iterative", n), n, |b, &n| {
+            b.iter(|| it_factorial_big(black_box(n)));
+        });
+        group.bench_with_input(BenchmarkId::new("recursive", n), n, |b, &n| {
+            b.iter(|| factorial_big(black_box(n)));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    benchmark_fib_iterative_big,
+    benchmark_fib_fast_doubling_big,
+    benchmark_factorial_big
+);
+criterion_main!(benches);
@@ -0,0 +1,25 @@
+use std::hint::black_box;
+
+use corex::factorial::{factorial, it_factorial, mem_factorial};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn benchmark_factorial(c: &mut Criterion) {
+    let mut group = c.benchmark_group("factorial");
+
+    for n in [0, 5, 10, 15, 20, 25, 30, 34].iter() {
+        group.bench_with_input(BenchmarkId::new("recursive", n), n, |b, &n| {
+            b.iter(|| factorial(black_box(n)));
+        });
+        group.bench_with_input(BenchmarkId::new("iterative", n), n, |b, &n| {
+            b.iter(|| it_factorial(black_box(n)));
+        });
+        group.bench_with_input(BenchmarkId::new("memoized", n), n, |b, &n| {
+            b.iter(|| mem_factorial(black_box(n)));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_factorial);
+criterion_main!(benches);
@@ -0,0 +1,170 @@
+// Run `cargo bench` first to populate `target/criterion/`, then
+// `cargo run --bin summary` to print a markdown comparison table.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct PointEstimate {
+    point_estimate: f64,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: PointEstimate,
+}
+
+struct Row {
+    algorithm: String,
+    mean_ns: f64,
+}
+
+fn main() {
+    let criterion_dir = Path::new("target/criterion");
+    if !criterion_dir.is_dir() {
+        eprintln!(
+            "no {} directory found; run `cargo bench` first",
+            criterion_dir.display()
+        );
+        std::process::exit(1);
+    }
+
+    let estimate_files = find_estimates_json(criterion_dir);
+    let mut by_family_and_input: BTreeMap<(String, u64), Vec<Row>> = BTreeMap::new();
+
+    for path in estimate_files {
+        let Some((family, input, algorithm)) = parse_benchmark_path(criterion_dir, &path) else {
+            continue;
+        };
+        let Ok(mean_ns) = read_mean_ns(&path) else {
+            continue;
+        };
+
+        by_family_and_input
+            .entry((family, input))
+            .or_default()
+            .push(Row { algorithm, mean_ns });
+    }
+
+    print_markdown_table(&by_family_and_input);
+}
+
+// Recursively collect every `new/estimates.json` under `target/criterion`.
+fn find_estimates_json(dir: &Path) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return found;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            found.extend(find_estimates_json(&path));
+        } else if path.file_name().map(|f| f == "estimates.json") == Some(true)
+            && path.parent().and_then(|p| p.file_name()) == Some(std::ffi::OsStr::new("new"))
+        {
+            found.push(path);
+        }
+    }
+
+    found
+}
+
+// Criterion lays benchmarks out as `<group>/[<function>/]<value>/new/estimates.json`.
+// The last path component before `new` is the input; everything before that
+// (joined back together) names the algorithm/group being compared.
+//
+// `<group>` always starts with the algorithm family it benchmarks
+// (`fibonacci_recursive`, `fibonacci_matrix`, `factorial`, `factorial_big`,
+// ...), so the leading word up to the first `_` gives a family key
+// (`fibonacci`, `factorial`) that "speedup vs slowest" is scoped to. Without
+// it, unrelated groups that happen to share an input value (e.g.
+// `factorial`'s n=10 and `fibonacci_recursive`'s n=10) would get compared
+// against each other as if they were variants of the same algorithm.
+//
+// The `_big` (BigUint) suites share that same leading word with their u64
+// counterparts (`fibonacci_iterative_big` -> `fibonacci`, same as
+// `fibonacci_recursive`), so a `_big` group is kept in its own family by
+// suffixing the leading word back onto the key. Otherwise a u64 bench and
+// its BigUint counterpart that happen to share an input (e.g. n=100) would
+// get compared as if they were the same kind of result, even though one of
+// them has silently overflowed.
+//
+// Groups that use `bench_function` instead of `bench_with_input` (e.g.
+// `fibonacci_comparison/recursive_30`) don't carry a separate input value —
+// the benchmark name bakes the input into itself. Those don't fit this
+// per-input table, so they're skipped rather than printed as a bogus row
+// with the benchmark name in the "input" column.
+fn parse_benchmark_path(
+    criterion_dir: &Path,
+    estimates_path: &Path,
+) -> Option<(String, u64, String)> {
+    let relative = estimates_path.strip_prefix(criterion_dir).ok()?;
+    let components: Vec<&str> = relative
+        .parent()? // .../new
+        .parent()? // .../<value>
+        .iter()
+        .map(|c| c.to_str().unwrap_or(""))
+        .collect();
+
+    let (input, algorithm_parts) = components.split_last()?;
+    if algorithm_parts.is_empty() {
+        return None;
+    }
+    let input = input.parse::<u64>().ok()?;
+    let leading_word = algorithm_parts[0]
+        .split('_')
+        .next()
+        .unwrap_or(algorithm_parts[0]);
+    let family = if algorithm_parts[0].ends_with("_big") {
+        format!("{leading_word}_big")
+    } else {
+        leading_word.to_string()
+    };
+
+    Some((family, input, algorithm_parts.join("::")))
+}
+
+fn read_mean_ns(path: &Path) -> Result<f64, std::io::Error> {
+    let contents = fs::read_to_string(path)?;
+    let estimates: Estimates = serde_json::from_str(&contents)?;
+    Ok(estimates.mean.point_estimate)
+}
+
+fn print_markdown_table(by_family_and_input: &BTreeMap<(String, u64), Vec<Row>>) {
+    println!("| Algorithm | Input | Mean Time | Speedup vs Slowest |");
+    println!("|---|---|---|---|");
+
+    for ((_family, input), rows) in by_family_and_input {
+        let slowest_ns = rows.iter().fold(0.0_f64, |acc, row| acc.max(row.mean_ns));
+
+        let mut sorted_rows: Vec<&Row> = rows.iter().collect();
+        sorted_rows.sort_by(|a, b| a.mean_ns.total_cmp(&b.mean_ns));
+
+        for row in sorted_rows {
+            let speedup = slowest_ns / row.mean_ns;
+            println!(
+                "| {} | {} | {} | {:.2}x |",
+                row.algorithm,
+                input,
+                format_duration_ns(row.mean_ns),
+                speedup
+            );
+        }
+    }
+}
+
+fn format_duration_ns(ns: f64) -> String {
+    if ns >= 1_000_000_000.0 {
+        format!("{:.3} s", ns / 1_000_000_000.0)
+    } else if ns >= 1_000_000.0 {
+        format!("{:.3} ms", ns / 1_000_000.0)
+    } else if ns >= 1_000.0 {
+        format!("{:.3} us", ns / 1_000.0)
+    } else {
+        format!("{:.3} ns", ns)
+    }
+}
@@ -1,37 +1,60 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
 use axum::{
-    extract::Path,
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
-    routing::{get, post},
+    routing::get,
     Json, Router,
 };
+use corex::factorial::it_factorial_big;
+use corex::fibonacci::{fib_fast_doubling, fib_iterative, fib_matrix};
+use corex::User;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize)]
-struct User {
-    name: String,
-    age: u8,
+// Keyed by `User::name`; good enough for an in-memory demo store.
+type UserStore = Arc<RwLock<HashMap<String, User>>>;
+
+#[derive(Clone, Default)]
+struct AppState {
+    users: UserStore,
 }
 
-#[derive(Serialize, Deserialize)]
-struct CreateUserRequest {
-    name: String,
-    age: u8,
+#[derive(Serialize)]
+struct ComputeResponse {
+    input: u64,
+    algorithm: String,
+    result: String,
+    elapsed_us: u128,
+}
+
+#[derive(Deserialize)]
+struct FibQuery {
+    algo: Option<String>,
 }
 
 #[tokio::main]
 async fn main() {
-    let app = Router::new()
-        .route("/", get(root))
-        .route("/health", get(health))
-        .route("/users", post(create_user))
-        .route("/users/{name}", get(get_user));
+    let app = build_app(AppState::default());
 
     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
     println!("Server running on http://0.0.0.0:3000");
     axum::serve(listener, app).await.unwrap();
 }
 
+fn build_app(state: AppState) -> Router {
+    Router::new()
+        .route("/", get(root))
+        .route("/health", get(health))
+        .route("/users", get(list_users).post(create_user))
+        .route("/users/{name}", get(get_user))
+        .route("/fib/{n}", get(fib))
+        .route("/factorial/{n}", get(factorial))
+        .with_state(state)
+}
+
 async fn root() -> &'static str {
     "Welcome to the Axum server!"
 }
@@ -40,31 +63,158 @@ async fn health() -> impl IntoResponse {
     (StatusCode::OK, "Healthy")
 }
 
-async fn create_user(Json(payload): Json<CreateUserRequest>) -> impl IntoResponse {
-    let user = User {
-        name: payload.name,
-        age: payload.age,
-    };
+async fn create_user(State(state): State<AppState>, Json(user): Json<User>) -> impl IntoResponse {
+    state
+        .users
+        .write()
+        .unwrap()
+        .insert(user.name.clone(), user.clone());
     (StatusCode::CREATED, Json(user))
 }
 
-async fn get_user(Path(name): Path<String>) -> impl IntoResponse {
-    let user = User {
-        name,
-        age: 25, // Default age
+async fn get_user(State(state): State<AppState>, Path(name): Path<String>) -> impl IntoResponse {
+    match state.users.read().unwrap().get(&name) {
+        Some(user) => Json(user.clone()).into_response(),
+        None => (StatusCode::NOT_FOUND, format!("user '{name}' not found")).into_response(),
+    }
+}
+
+async fn list_users(State(state): State<AppState>) -> impl IntoResponse {
+    let users: Vec<User> = state.users.read().unwrap().values().cloned().collect();
+    Json(users)
+}
+
+// Fibonacci overflows u64 past n=93, so anything beyond that (or an
+// unknown algorithm) is rejected rather than silently wrapping.
+const FIB_U64_MAX_N: u64 = 93;
+
+async fn fib(Path(n): Path<u64>, Query(query): Query<FibQuery>) -> impl IntoResponse {
+    let algorithm = query.algo.unwrap_or_else(|| "iterative".to_string());
+
+    if n > FIB_U64_MAX_N {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("n must be <= {FIB_U64_MAX_N} (u64 overflow)"),
+        )
+            .into_response();
+    }
+
+    let Ok(n32) = u32::try_from(n) else {
+        return (StatusCode::BAD_REQUEST, "n is out of range").into_response();
+    };
+
+    let start = Instant::now();
+    let result = match algorithm.as_str() {
+        "iterative" => fib_iterative(n32),
+        "matrix" => fib_matrix(n32),
+        "fast_doubling" => fib_fast_doubling(n32),
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("unknown algo '{algorithm}', expected iterative|matrix|fast_doubling"),
+            )
+                .into_response();
+        }
+    };
+    let elapsed_us = start.elapsed().as_micros();
+
+    Json(ComputeResponse {
+        input: n,
+        algorithm,
+        result: result.to_string(),
+        elapsed_us,
+    })
+    .into_response()
+}
+
+// Factorial results grow far beyond u64, so this always uses the BigUint
+// implementation; `n` is still capped to keep the response time bounded.
+const FACTORIAL_MAX_N: u64 = 100_000;
+
+async fn factorial(Path(n): Path<u64>) -> impl IntoResponse {
+    if n > FACTORIAL_MAX_N {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("n must be <= {FACTORIAL_MAX_N}"),
+        )
+            .into_response();
+    }
+
+    // it_factorial_big(n) can take seconds at the top of the allowed range,
+    // so it runs on a blocking-pool thread instead of stalling the tokio
+    // worker that's handling this request.
+    let start = Instant::now();
+    let Ok(result) = tokio::task::spawn_blocking(move || it_factorial_big(n)).await else {
+        return (StatusCode::INTERNAL_SERVER_ERROR, "computation failed").into_response();
     };
-    Json(user)
+    let elapsed_us = start.elapsed().as_micros();
+
+    Json(ComputeResponse {
+        input: n,
+        algorithm: "iterative_big".to_string(),
+        result: result.to_string(),
+        elapsed_us,
+    })
+    .into_response()
 }
 
 #[cfg(test)]
 mod tests {
-    #[test]
-    fn it_works() {
-     assert!(true); 
+    use axum::body::Body;
+    use axum::http::Request;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn create_then_fetch_user_round_trips() {
+        let app = build_app(AppState::default());
+
+        let create_response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/users")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"name":"ada","age":36}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(create_response.status(), StatusCode::CREATED);
+
+        let get_response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/users/ada")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(get_response.status(), StatusCode::OK);
+
+        let body = get_response.into_body().collect().await.unwrap().to_bytes();
+        let user: User = serde_json::from_slice(&body).unwrap();
+        assert_eq!(user.name, "ada");
+        assert_eq!(user.age, 36);
     }
 
-    #[test]
-    fn sure_it_does() {
-       assert!(true); 
+    #[tokio::test]
+    async fn get_missing_user_returns_404() {
+        let app = build_app(AppState::default());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/users/nobody")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 }